@@ -350,6 +350,7 @@ fn derive_new_contents_from_chunks(
         }
     };
 
+    let uses_crlf = uses_crlf_line_endings(&original_contents);
     let mut original_lines: Vec<String> = original_contents.split('\n').map(String::from).collect();
 
     // Drop the trailing empty element that results from the final newline so
@@ -358,19 +359,37 @@ fn derive_new_contents_from_chunks(
         original_lines.pop();
     }
 
+    // Patches are always authored with `\n` endings. Match against the bare
+    // lines and restore the file's CRLF endings when writing it back so that
+    // editing a Windows-authored file does not rewrite every line.
+    if uses_crlf {
+        for line in &mut original_lines {
+            if line.ends_with('\r') {
+                line.pop();
+            }
+        }
+    }
+
     let replacements = compute_replacements(&original_lines, path, chunks)?;
     let new_lines = apply_replacements(original_lines, &replacements);
     let mut new_lines = new_lines;
     if !new_lines.last().is_some_and(String::is_empty) {
         new_lines.push(String::new());
     }
-    let new_contents = new_lines.join("\n");
+    let new_contents = new_lines.join(if uses_crlf { "\r\n" } else { "\n" });
     Ok(AppliedPatch {
         original_contents,
         new_contents,
     })
 }
 
+/// Returns true when CRLF is the dominant line ending in `contents`.
+fn uses_crlf_line_endings(contents: &str) -> bool {
+    let crlf = contents.matches("\r\n").count();
+    let lf = contents.matches('\n').count() - crlf;
+    crlf > lf
+}
+
 /// Compute a list of replacements needed to transform `original_lines` into the
 /// new lines, given the patch `chunks`. Each replacement is returned as
 /// `(start_index, old_len, new_lines)`.
@@ -630,6 +649,35 @@ mod tests {
         assert_eq!(contents, "foo\nbaz\n");
     }
 
+    #[test]
+    fn test_update_file_hunk_preserves_crlf_line_endings() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("crlf.txt");
+        fs::write(&path, "foo\r\nbar\r\nqux\r\n").unwrap();
+        let patch = wrap_patch(&format!(
+            r#"*** Update File: {}
+@@
+ foo
+-bar
++baz
++extra"#,
+            path.display()
+        ));
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        apply_patch(&patch, &mut stdout, &mut stderr).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        assert_eq!(contents, "foo\r\nbaz\r\nextra\r\nqux\r\n");
+    }
+
+    #[test]
+    fn test_uses_crlf_line_endings_picks_dominant_ending() {
+        assert!(uses_crlf_line_endings("a\r\nb\r\nc\n"));
+        assert!(!uses_crlf_line_endings("a\nb\nc\r\n"));
+        assert!(!uses_crlf_line_endings("a\nb\n"));
+        assert!(!uses_crlf_line_endings("no newline"));
+    }
+
     #[test]
     fn test_update_file_hunk_can_move_file() {
         let dir = tempdir().unwrap();
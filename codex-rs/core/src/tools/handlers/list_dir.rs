@@ -1,11 +1,21 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::ffi::OsStr;
 use std::fs::FileType;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::SystemTime;
 
 use async_trait::async_trait;
 use codex_utils_string::take_bytes_at_char_boundary;
+use globset::GlobBuilder;
+use globset::GlobMatcher;
+use ignore::Match;
+use ignore::gitignore::Gitignore;
+use ignore::gitignore::GitignoreBuilder;
 use serde::Deserialize;
 use tokio::fs;
 
@@ -20,19 +30,39 @@ pub struct ListDirHandler;
 
 const MAX_ENTRY_LENGTH: usize = 500;
 const INDENTATION_SPACES: usize = 2;
+const IGNORE_FILE_NAMES: &[&str] = &[".gitignore", ".ignore"];
+/// Metadata files created by operating systems and file managers. They never
+/// carry project content, so they are always skipped.
+const OS_METADATA_FILE_NAMES: &[&str] = &[
+    ".DS_Store",
+    ".AppleDouble",
+    ".Spotlight-V100",
+    ".Trashes",
+    "Thumbs.db",
+    "ehthumbs.db",
+    "desktop.ini",
+];
 
 fn default_offset() -> usize {
     1
 }
 
 fn default_limit() -> usize {
-    25
+    500
 }
 
 fn default_depth() -> usize {
     2
 }
 
+fn default_respect_gitignore() -> bool {
+    true
+}
+
+fn default_include_hidden() -> bool {
+    true
+}
+
 #[derive(Deserialize)]
 struct ListDirArgs {
     dir_path: String,
@@ -42,6 +72,169 @@ struct ListDirArgs {
     limit: usize,
     #[serde(default = "default_depth")]
     depth: usize,
+    #[serde(default = "default_respect_gitignore")]
+    respect_gitignore: bool,
+    #[serde(default = "default_include_hidden")]
+    include_hidden: bool,
+    #[serde(default)]
+    follow_symlinks: bool,
+    #[serde(default)]
+    pattern: Option<String>,
+    #[serde(default)]
+    entry_type: Option<EntryTypeFilter>,
+    #[serde(default)]
+    sort_by: SortBy,
+    #[serde(default)]
+    sort_order: SortOrder,
+    #[serde(default)]
+    compute_dir_sizes: bool,
+    #[serde(default)]
+    output: Option<OutputMode>,
+}
+
+#[derive(Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum EntryTypeFilter {
+    File,
+    Directory,
+}
+
+#[derive(Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum SortBy {
+    #[default]
+    Name,
+    Size,
+    Modified,
+}
+
+#[derive(Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+#[derive(Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum OutputMode {
+    /// One relative path per line.
+    Entries,
+    /// Entries indented beneath their parent directory.
+    Tree,
+    /// Aggregate file counts and sizes instead of individual entries.
+    Summary,
+}
+
+/// Filters applied while walking the directory tree.
+#[derive(Clone)]
+struct ListDirOptions {
+    /// Skip entries matched by `.gitignore`/`.ignore` files in the listed
+    /// tree and, inside a git repository, its ancestors up to the repo root.
+    respect_gitignore: bool,
+    /// List dotfiles and walk into dot-directories.
+    include_hidden: bool,
+    /// Walk into symlinked directories. Links back to a directory already on
+    /// the current branch of the walk are listed but not followed.
+    follow_symlinks: bool,
+    /// Only list entries whose path relative to the listed directory matches.
+    pattern: Option<GlobMatcher>,
+    /// Only list entries of this kind.
+    entry_type: Option<EntryTypeFilter>,
+    /// Key the whole listing is ordered by before paging.
+    sort_by: SortBy,
+    sort_order: SortOrder,
+    /// Report directories as the total size of the files beneath them (within
+    /// the walked depth) instead of 0 when sorting by size.
+    compute_dir_sizes: bool,
+    /// Requested rendering. When omitted, listings are rendered as a tree
+    /// unless filtering or sorting requires relative paths.
+    output: Option<OutputMode>,
+}
+
+impl ListDirOptions {
+    /// Filtered and re-sorted listings no longer keep children next to their
+    /// parents, so entries are rendered as relative paths instead of an
+    /// indented tree.
+    fn flattens_output(&self) -> bool {
+        self.output == Some(OutputMode::Entries) || self.reorders_entries()
+    }
+
+    /// An explicit tree cannot be honored once entries are filtered or
+    /// re-sorted away from their parents.
+    fn validate(&self) -> Result<(), FunctionCallError> {
+        if self.output == Some(OutputMode::Tree) && self.reorders_entries() {
+            return Err(FunctionCallError::RespondToModel(
+                "output \"tree\" cannot be combined with pattern, entry_type, or sorting; use \"entries\" instead".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn reorders_entries(&self) -> bool {
+        self.pattern.is_some() || self.entry_type.is_some() || self.sorts_entries()
+    }
+
+    fn needs_metadata(&self) -> bool {
+        self.sorts_entries() || self.output == Some(OutputMode::Summary)
+    }
+
+    /// Whether the listing is ordered by something other than the default
+    /// breadth-first, name-ascending walk.
+    fn sorts_entries(&self) -> bool {
+        self.sort_by != SortBy::Name || self.sort_order != SortOrder::Asc
+    }
+
+    fn compare(&self, a: &DirEntry, b: &DirEntry) -> Ordering {
+        let ordering = match self.sort_by {
+            SortBy::Name => a.name.cmp(&b.name),
+            SortBy::Size => a.size.unwrap_or(0).cmp(&b.size.unwrap_or(0)),
+            SortBy::Modified => a.modified.cmp(&b.modified),
+        };
+        let ordering = match self.sort_order {
+            SortOrder::Asc => ordering,
+            SortOrder::Desc => ordering.reverse(),
+        };
+        ordering.then_with(|| a.name.cmp(&b.name))
+    }
+
+    fn includes(&self, relative_path: &Path, kind: DirEntryKind) -> bool {
+        let type_matches = match self.entry_type {
+            Some(EntryTypeFilter::File) => kind == DirEntryKind::File,
+            Some(EntryTypeFilter::Directory) => kind == DirEntryKind::Directory,
+            None => true,
+        };
+        type_matches
+            && self
+                .pattern
+                .as_ref()
+                .is_none_or(|pattern| pattern.is_match(relative_path))
+    }
+}
+
+impl Default for ListDirOptions {
+    fn default() -> Self {
+        Self {
+            respect_gitignore: default_respect_gitignore(),
+            include_hidden: default_include_hidden(),
+            follow_symlinks: false,
+            pattern: None,
+            entry_type: None,
+            sort_by: SortBy::default(),
+            sort_order: SortOrder::default(),
+            compute_dir_sizes: false,
+            output: None,
+        }
+    }
+}
+
+fn compile_pattern(pattern: &str) -> Result<GlobMatcher, FunctionCallError> {
+    GlobBuilder::new(pattern)
+        .literal_separator(true)
+        .build()
+        .map(|glob| glob.compile_matcher())
+        .map_err(|err| FunctionCallError::RespondToModel(format!("invalid pattern: {err}")))
 }
 
 #[async_trait]
@@ -73,6 +266,15 @@ impl ToolHandler for ListDirHandler {
             offset,
             limit,
             depth,
+            respect_gitignore,
+            include_hidden,
+            follow_symlinks,
+            pattern,
+            entry_type,
+            sort_by,
+            sort_order,
+            compute_dir_sizes,
+            output,
         } = args;
 
         if offset == 0 {
@@ -100,7 +302,20 @@ impl ToolHandler for ListDirHandler {
             ));
         }
 
-        let entries = list_dir_slice(&path, offset, limit, depth).await?;
+        let pattern = pattern.as_deref().map(compile_pattern).transpose()?;
+        let options = ListDirOptions {
+            respect_gitignore,
+            include_hidden,
+            follow_symlinks,
+            pattern,
+            entry_type,
+            sort_by,
+            sort_order,
+            compute_dir_sizes,
+            output,
+        };
+        options.validate()?;
+        let entries = list_dir_slice(&path, offset, limit, depth, &options).await?;
         let mut output = Vec::with_capacity(entries.len() + 1);
         output.push(format!("Absolute path: {}", path.display()));
         output.extend(entries);
@@ -117,12 +332,19 @@ async fn list_dir_slice(
     offset: usize,
     limit: usize,
     depth: usize,
+    options: &ListDirOptions,
 ) -> Result<Vec<String>, FunctionCallError> {
     let mut entries = Vec::new();
-    collect_entries(path, Path::new(""), depth, &mut entries).await?;
+    let skipped = collect_entries(path, Path::new(""), depth, options, &mut entries).await?;
+
+    if options.output == Some(OutputMode::Summary) {
+        let mut summary = summarize_entries(&entries, depth);
+        summary.extend(skipped.notices());
+        return Ok(summary);
+    }
 
     if entries.is_empty() {
-        return Ok(Vec::new());
+        return Ok(skipped.notices());
     }
 
     let start_index = offset - 1;
@@ -132,34 +354,209 @@ async fn list_dir_slice(
         ));
     }
 
+    if options.sorts_entries() {
+        entries.sort_by(|a, b| options.compare(a, b));
+    }
+
     let remaining_entries = entries.len() - start_index;
     let capped_limit = limit.min(remaining_entries);
     let end_index = start_index + capped_limit;
     let mut selected_entries = entries[start_index..end_index].to_vec();
-    selected_entries.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    if !options.sorts_entries() {
+        selected_entries.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    }
     let mut formatted = Vec::with_capacity(selected_entries.len());
 
     for entry in &selected_entries {
-        formatted.push(format_entry_line(entry));
+        let line = format_entry_line(entry);
+        match entry.size {
+            Some(size) if options.sort_by == SortBy::Size => {
+                formatted.push(format!("{line} ({size} bytes)"));
+            }
+            _ => formatted.push(line),
+        }
     }
 
     if end_index < entries.len() {
-        formatted.push(format!("More than {capped_limit} entries found"));
+        let total = entries.len();
+        let next_offset = end_index + 1;
+        formatted.push(format!(
+            "More than {capped_limit} entries found ({total} total); continue with offset {next_offset}"
+        ));
     }
 
+    formatted.extend(skipped.notices());
+
     Ok(formatted)
 }
 
+#[derive(Default)]
+struct SizeTotals {
+    files: usize,
+    bytes: u64,
+}
+
+impl SizeTotals {
+    fn add(&mut self, bytes: u64) {
+        self.files += 1;
+        self.bytes += bytes;
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "{}, {}",
+            count_noun(self.files, "file", "files"),
+            count_noun(self.bytes, "byte", "bytes")
+        )
+    }
+}
+
+fn count_noun<T>(count: T, singular: &str, plural: &str) -> String
+where
+    T: std::fmt::Display + PartialEq + From<u8>,
+{
+    if count == T::from(1) {
+        format!("{count} {singular}")
+    } else {
+        format!("{count} {plural}")
+    }
+}
+
+/// Aggregates the listed files by top-level directory and by extension, both
+/// ordered largest first.
+fn summarize_entries(entries: &[DirEntry], depth: usize) -> Vec<String> {
+    let mut total = SizeTotals::default();
+    let mut by_top_level: BTreeMap<String, SizeTotals> = BTreeMap::new();
+    let mut by_extension: BTreeMap<String, SizeTotals> = BTreeMap::new();
+
+    for entry in entries
+        .iter()
+        .filter(|entry| entry.kind == DirEntryKind::File)
+    {
+        let bytes = entry.size.unwrap_or(0);
+        total.add(bytes);
+        let top_level = match entry.name.split_once('/') {
+            Some((dir, _)) => format!("{dir}/"),
+            None => "./".to_string(),
+        };
+        by_top_level.entry(top_level).or_default().add(bytes);
+        let extension = Path::new(&entry.name)
+            .extension()
+            .map(|extension| format!(".{}", extension.to_string_lossy()))
+            .unwrap_or_else(|| "(no extension)".to_string());
+        by_extension.entry(extension).or_default().add(bytes);
+    }
+
+    let mut lines = vec![format!(
+        "Summary of {} ({}) within depth {depth}",
+        count_noun(total.files, "file", "files"),
+        count_noun(total.bytes, "byte", "bytes")
+    )];
+    for (heading, groups) in [
+        ("By top-level directory:", by_top_level),
+        ("By extension:", by_extension),
+    ] {
+        if groups.is_empty() {
+            continue;
+        }
+        let mut groups: Vec<_> = groups.into_iter().collect();
+        groups.sort_by(|(_, a), (_, b)| b.bytes.cmp(&a.bytes));
+        lines.push(heading.to_string());
+        lines.extend(
+            groups
+                .into_iter()
+                .map(|(name, totals)| format!("  {name}: {}", totals.describe())),
+        );
+    }
+    lines
+}
+
+/// Counts of entries left out of a listing, reported so the model knows they
+/// exist. Skipped directories count once and are not walked.
+#[derive(Clone, Copy, Default)]
+struct SkippedEntries {
+    ignored: usize,
+    hidden: usize,
+    os_metadata: usize,
+}
+
+impl SkippedEntries {
+    fn notices(&self) -> Vec<String> {
+        let Self {
+            ignored,
+            hidden,
+            os_metadata,
+        } = *self;
+        let mut notices = Vec::new();
+        if ignored > 0 {
+            notices.push(format!(
+                "Skipped {} matched by .gitignore or .ignore (set respect_gitignore to false to include them)",
+                count_noun(ignored, "entry", "entries")
+            ));
+        }
+        if hidden > 0 {
+            notices.push(format!(
+                "Skipped {} (set include_hidden to true to include them)",
+                count_noun(hidden, "hidden entry", "hidden entries")
+            ));
+        }
+        if os_metadata > 0 {
+            notices.push(format!(
+                "Skipped {} such as .DS_Store",
+                count_noun(os_metadata, "OS metadata entry", "OS metadata entries")
+            ));
+        }
+        notices
+    }
+}
+
+fn is_os_metadata(name: &OsStr) -> bool {
+    OS_METADATA_FILE_NAMES
+        .iter()
+        .any(|junk| name == OsStr::new(junk))
+}
+
+fn is_hidden(name: &OsStr) -> bool {
+    name.to_string_lossy().starts_with('.')
+}
+
+/// Walks `dir_path` breadth-first, appending entries and returning how many
+/// were left out by ignore files and the hidden/metadata filters.
 async fn collect_entries(
     dir_path: &Path,
     relative_prefix: &Path,
     depth: usize,
+    options: &ListDirOptions,
     entries: &mut Vec<DirEntry>,
-) -> Result<(), FunctionCallError> {
+) -> Result<SkippedEntries, FunctionCallError> {
+    let root_matchers = if options.respect_gitignore {
+        ancestor_ignore_matchers(dir_path).await
+    } else {
+        Vec::new()
+    };
+    let mut skipped = SkippedEntries::default();
+    let mut dir_sizes: HashMap<String, u64> = HashMap::new();
+    let canonical_root = fs::canonicalize(dir_path)
+        .await
+        .unwrap_or_else(|_| dir_path.to_path_buf());
     let mut queue = VecDeque::new();
-    queue.push_back((dir_path.to_path_buf(), relative_prefix.to_path_buf(), depth));
+    queue.push_back((
+        dir_path.to_path_buf(),
+        relative_prefix.to_path_buf(),
+        depth,
+        root_matchers,
+        vec![canonical_root],
+    ));
+
+    while let Some((current_dir, prefix, remaining_depth, mut matchers, ancestors)) =
+        queue.pop_front()
+    {
+        if options.respect_gitignore
+            && let Some(matcher) = load_ignore_matcher(&current_dir).await
+        {
+            matchers.push(Arc::new(matcher));
+        }
 
-    while let Some((current_dir, prefix, remaining_depth)) = queue.pop_front() {
         let mut read_dir = fs::read_dir(&current_dir).await.map_err(|err| {
             FunctionCallError::RespondToModel(format!("failed to read directory: {err}"))
         })?;
@@ -173,41 +570,211 @@ async fn collect_entries(
                 FunctionCallError::RespondToModel(format!("failed to inspect entry: {err}"))
             })?;
 
+            let kind = DirEntryKind::from(&file_type);
             let file_name = entry.file_name();
+            if is_os_metadata(&file_name) {
+                skipped.os_metadata += 1;
+                continue;
+            }
+            if !options.include_hidden && is_hidden(&file_name) {
+                skipped.hidden += 1;
+                continue;
+            }
+
+            let entry_path = entry.path();
+            if is_ignored(&matchers, &entry_path, kind == DirEntryKind::Directory) {
+                skipped.ignored += 1;
+                continue;
+            }
+
             let relative_path = if prefix.as_os_str().is_empty() {
                 PathBuf::from(&file_name)
             } else {
                 prefix.join(&file_name)
             };
 
-            let display_name = format_entry_component(&file_name);
-            let display_depth = prefix.components().count();
+            let (size, modified) = if options.needs_metadata() {
+                let metadata = entry.metadata().await.map_err(|err| {
+                    FunctionCallError::RespondToModel(format!("failed to inspect entry: {err}"))
+                })?;
+                let size = (kind != DirEntryKind::Directory).then_some(metadata.len());
+                (size, metadata.modified().ok())
+            } else {
+                (None, None)
+            };
+
+            if options.compute_dir_sizes
+                && let Some(size) = size
+            {
+                for ancestor in relative_path.ancestors().skip(1) {
+                    if ancestor.as_os_str().is_empty() {
+                        break;
+                    }
+                    *dir_sizes.entry(format_entry_name(ancestor)).or_default() += size;
+                }
+            }
+
+            // Canonical paths of the directories on this branch let symlink
+            // loops such as `a -> ..` be detected without a global visited set,
+            // which would hide real directories reached through an alias first.
+            let (target, walk_into) = match kind {
+                DirEntryKind::Directory => (None, ancestors.last().map(|dir| dir.join(&file_name))),
+                DirEntryKind::Symlink => {
+                    resolve_symlink(&entry_path, &ancestors, options.follow_symlinks).await
+                }
+                _ => (None, None),
+            };
+
             let sort_key = format_entry_name(&relative_path);
-            let kind = DirEntryKind::from(&file_type);
+            let (display_name, display_depth) = if options.flattens_output() {
+                (sort_key.clone(), 0)
+            } else {
+                (
+                    format_entry_component(&file_name),
+                    prefix.components().count(),
+                )
+            };
             dir_entries.push((
-                entry.path(),
+                entry_path,
                 relative_path,
-                kind,
+                walk_into,
                 DirEntry {
                     name: sort_key,
                     display_name,
                     depth: display_depth,
                     kind,
+                    size,
+                    modified,
+                    target,
                 },
             ));
         }
 
         dir_entries.sort_unstable_by(|a, b| a.3.name.cmp(&b.3.name));
 
-        for (entry_path, relative_path, kind, dir_entry) in dir_entries {
-            if kind == DirEntryKind::Directory && remaining_depth > 1 {
-                queue.push_back((entry_path, relative_path, remaining_depth - 1));
+        for (entry_path, relative_path, walk_into, dir_entry) in dir_entries {
+            let include = options.includes(&relative_path, dir_entry.kind);
+            // Directories are walked even when filtered out so that matches
+            // nested beneath them are still found.
+            if let Some(canonical_path) = walk_into
+                && remaining_depth > 1
+            {
+                let mut child_ancestors = ancestors.clone();
+                child_ancestors.push(canonical_path);
+                queue.push_back((
+                    entry_path,
+                    relative_path,
+                    remaining_depth - 1,
+                    matchers.clone(),
+                    child_ancestors,
+                ));
+            }
+            if include {
+                entries.push(dir_entry);
             }
-            entries.push(dir_entry);
         }
     }
 
-    Ok(())
+    if options.compute_dir_sizes {
+        for entry in entries
+            .iter_mut()
+            .filter(|entry| entry.kind == DirEntryKind::Directory)
+        {
+            entry.size = Some(dir_sizes.get(&entry.name).copied().unwrap_or(0));
+        }
+    }
+
+    Ok(skipped)
+}
+
+/// Returns the link target for display and, when `follow` is set and the link
+/// resolves to a directory that is not one of `ancestors`, the canonical path
+/// to walk.
+async fn resolve_symlink(
+    path: &Path,
+    ancestors: &[PathBuf],
+    follow: bool,
+) -> (Option<String>, Option<PathBuf>) {
+    let target = fs::read_link(path)
+        .await
+        .ok()
+        .map(|target| format_entry_name(&target));
+    if !follow {
+        return (target, None);
+    }
+    let is_dir = fs::metadata(path)
+        .await
+        .is_ok_and(|metadata| metadata.is_dir());
+    if !is_dir {
+        return (target, None);
+    }
+    let walk_into = fs::canonicalize(path)
+        .await
+        .ok()
+        .filter(|canonical| !ancestors.contains(canonical));
+    (target, walk_into)
+}
+
+/// Builds a matcher from the ignore files directly inside `dir`, if any.
+async fn load_ignore_matcher(dir: &Path) -> Option<Gitignore> {
+    let mut builder = GitignoreBuilder::new(dir);
+    let mut has_rules = false;
+    for name in IGNORE_FILE_NAMES {
+        let ignore_path = dir.join(name);
+        let Ok(contents) = fs::read_to_string(&ignore_path).await else {
+            continue;
+        };
+        for line in contents.lines() {
+            // Like git, skip patterns that fail to parse instead of failing the listing.
+            if builder.add_line(Some(ignore_path.clone()), line).is_ok() {
+                has_rules = true;
+            }
+        }
+    }
+
+    if !has_rules {
+        return None;
+    }
+    builder.build().ok()
+}
+
+/// Collects ignore matchers from the ancestors of `dir_path` up to the
+/// enclosing git repository root, ordered from outermost to innermost. Outside
+/// a repository only the listed tree's own ignore files apply.
+async fn ancestor_ignore_matchers(dir_path: &Path) -> Vec<Arc<Gitignore>> {
+    if is_git_root(dir_path).await {
+        return Vec::new();
+    }
+
+    let mut matchers = Vec::new();
+    for ancestor in dir_path.ancestors().skip(1) {
+        if let Some(matcher) = load_ignore_matcher(ancestor).await {
+            matchers.push(Arc::new(matcher));
+        }
+        if is_git_root(ancestor).await {
+            matchers.reverse();
+            return matchers;
+        }
+    }
+
+    Vec::new()
+}
+
+async fn is_git_root(dir: &Path) -> bool {
+    fs::try_exists(dir.join(".git")).await.unwrap_or(false)
+}
+
+/// Deeper ignore files take precedence, so the innermost matcher with an
+/// opinion decides whether `path` is ignored.
+fn is_ignored(matchers: &[Arc<Gitignore>], path: &Path, is_dir: bool) -> bool {
+    for matcher in matchers.iter().rev() {
+        match matcher.matched(path, is_dir) {
+            Match::Ignore(_) => return true,
+            Match::Whitelist(_) => return false,
+            Match::None => {}
+        }
+    }
+    false
 }
 
 fn format_entry_name(path: &Path) -> String {
@@ -237,7 +804,10 @@ fn format_entry_line(entry: &DirEntry) -> String {
         DirEntryKind::Other => name.push('?'),
         DirEntryKind::File => {}
     }
-    format!("{indent}{name}")
+    match &entry.target {
+        Some(target) => format!("{indent}{name} -> {target}"),
+        None => format!("{indent}{name}"),
+    }
 }
 
 #[derive(Clone)]
@@ -246,6 +816,12 @@ struct DirEntry {
     display_name: String,
     depth: usize,
     kind: DirEntryKind,
+    /// Only populated when the listing is sorted; directories have no size
+    /// unless `compute_dir_sizes` is set.
+    size: Option<u64>,
+    modified: Option<SystemTime>,
+    /// Symlink target as stored in the link, whether or not it is followed.
+    target: Option<String>,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -307,14 +883,14 @@ mod tests {
             symlink(dir_path.join("entry.txt"), &link_path).expect("create symlink");
         }
 
-        let entries = list_dir_slice(dir_path, 1, 20, 3)
+        let entries = list_dir_slice(dir_path, 1, 20, 3, &ListDirOptions::default())
             .await
             .expect("list directory");
 
         #[cfg(unix)]
         let expected = vec![
             "entry.txt".to_string(),
-            "link@".to_string(),
+            format!("link@ -> {}", dir_path.join("entry.txt").display()),
             "nested/".to_string(),
             "  child.txt".to_string(),
             "  deeper/".to_string(),
@@ -341,7 +917,7 @@ mod tests {
             .await
             .expect("create sub dir");
 
-        let err = list_dir_slice(dir_path, 10, 1, 2)
+        let err = list_dir_slice(dir_path, 10, 1, 2, &ListDirOptions::default())
             .await
             .expect_err("offset exceeds entries");
         assert_eq!(
@@ -368,7 +944,7 @@ mod tests {
             .await
             .expect("write deeper");
 
-        let entries_depth_one = list_dir_slice(dir_path, 1, 10, 1)
+        let entries_depth_one = list_dir_slice(dir_path, 1, 10, 1, &ListDirOptions::default())
             .await
             .expect("list depth 1");
         assert_eq!(
@@ -376,7 +952,7 @@ mod tests {
             vec!["nested/".to_string(), "root.txt".to_string(),]
         );
 
-        let entries_depth_two = list_dir_slice(dir_path, 1, 20, 2)
+        let entries_depth_two = list_dir_slice(dir_path, 1, 20, 2, &ListDirOptions::default())
             .await
             .expect("list depth 2");
         assert_eq!(
@@ -389,7 +965,7 @@ mod tests {
             ]
         );
 
-        let entries_depth_three = list_dir_slice(dir_path, 1, 30, 3)
+        let entries_depth_three = list_dir_slice(dir_path, 1, 30, 3, &ListDirOptions::default())
             .await
             .expect("list depth 3");
         assert_eq!(
@@ -418,7 +994,7 @@ mod tests {
             .await
             .expect("write gamma");
 
-        let entries = list_dir_slice(dir_path, 2, usize::MAX, 1)
+        let entries = list_dir_slice(dir_path, 2, usize::MAX, 1, &ListDirOptions::default())
             .await
             .expect("list without overflow");
         assert_eq!(
@@ -439,14 +1015,488 @@ mod tests {
                 .expect("write file");
         }
 
-        let entries = list_dir_slice(dir_path, 1, 25, 1)
+        let entries = list_dir_slice(dir_path, 1, 25, 1, &ListDirOptions::default())
             .await
             .expect("list directory");
         assert_eq!(entries.len(), 26);
         assert_eq!(
             entries.last(),
-            Some(&"More than 25 entries found".to_string())
+            Some(&"More than 25 entries found (40 total); continue with offset 26".to_string())
+        );
+
+        let last_page = list_dir_slice(dir_path, 26, 25, 1, &ListDirOptions::default())
+            .await
+            .expect("list last page");
+        assert_eq!(last_page.len(), 15);
+        assert_eq!(last_page.first(), Some(&"file_25.txt".to_string()));
+        assert_eq!(last_page.last(), Some(&"file_39.txt".to_string()));
+    }
+
+    #[tokio::test]
+    async fn skips_gitignored_entries_by_default() -> anyhow::Result<()> {
+        let temp = tempdir()?;
+        let dir_path = temp.path();
+        tokio::fs::write(dir_path.join(".gitignore"), b"target/\n*.log\n!keep.log\n").await?;
+        tokio::fs::create_dir(dir_path.join("target")).await?;
+        tokio::fs::write(dir_path.join("target").join("out.bin"), b"bin").await?;
+        tokio::fs::create_dir(dir_path.join("src")).await?;
+        tokio::fs::write(dir_path.join("src").join("main.rs"), b"fn main() {}").await?;
+        tokio::fs::write(dir_path.join("src").join("debug.log"), b"log").await?;
+        tokio::fs::write(dir_path.join("keep.log"), b"log").await?;
+
+        let entries = list_dir_slice(dir_path, 1, 20, 3, &ListDirOptions::default()).await?;
+        assert_eq!(
+            entries,
+            vec![
+                ".gitignore".to_string(),
+                "keep.log".to_string(),
+                "src/".to_string(),
+                "  main.rs".to_string(),
+                "Skipped 2 entries matched by .gitignore or .ignore (set respect_gitignore to false to include them)".to_string(),
+            ]
+        );
+
+        let options = ListDirOptions {
+            respect_gitignore: false,
+            ..Default::default()
+        };
+        let entries = list_dir_slice(dir_path, 1, 20, 3, &options).await?;
+        assert_eq!(
+            entries,
+            vec![
+                ".gitignore".to_string(),
+                "keep.log".to_string(),
+                "src/".to_string(),
+                "  debug.log".to_string(),
+                "  main.rs".to_string(),
+                "target/".to_string(),
+                "  out.bin".to_string(),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn applies_ignore_files_from_repo_ancestors() -> anyhow::Result<()> {
+        let temp = tempdir()?;
+        let repo = temp.path();
+        tokio::fs::create_dir(repo.join(".git")).await?;
+        tokio::fs::write(repo.join(".gitignore"), b"generated/\n").await?;
+        let pkg = repo.join("pkg");
+        tokio::fs::create_dir_all(pkg.join("generated")).await?;
+        tokio::fs::write(pkg.join("generated").join("bindings.rs"), b"").await?;
+        tokio::fs::write(pkg.join(".ignore"), b"scratch.txt\n").await?;
+        tokio::fs::write(pkg.join("scratch.txt"), b"").await?;
+        tokio::fs::write(pkg.join("lib.rs"), b"").await?;
+
+        let entries = list_dir_slice(&pkg, 1, 20, 2, &ListDirOptions::default()).await?;
+        assert_eq!(
+            entries,
+            vec![
+                ".ignore".to_string(),
+                "lib.rs".to_string(),
+                "Skipped 2 entries matched by .gitignore or .ignore (set respect_gitignore to false to include them)".to_string(),
+            ]
+        );
+        Ok(())
+    }
+
+    async fn write_filter_fixture(dir_path: &Path) -> anyhow::Result<()> {
+        tokio::fs::create_dir_all(dir_path.join("src").join("lib").join("deep")).await?;
+        tokio::fs::create_dir(dir_path.join("build")).await?;
+        tokio::fs::write(dir_path.join("README.md"), b"readme").await?;
+        tokio::fs::write(dir_path.join("top.rs"), b"").await?;
+        tokio::fs::write(dir_path.join("src").join("main.rs"), b"").await?;
+        tokio::fs::write(dir_path.join("src").join("notes.txt"), b"").await?;
+        tokio::fs::write(dir_path.join("src").join("lib").join("mod.rs"), b"").await?;
+        tokio::fs::write(
+            dir_path.join("src").join("lib").join("deep").join("x.rs"),
+            b"",
+        )
+        .await?;
+        tokio::fs::write(dir_path.join("build").join("gen.rs"), b"").await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn filters_entries_by_glob_pattern_within_depth() -> anyhow::Result<()> {
+        let temp = tempdir()?;
+        let dir_path = temp.path();
+        write_filter_fixture(dir_path).await?;
+
+        let options = ListDirOptions {
+            pattern: Some(compile_pattern("**/*.rs")?),
+            ..Default::default()
+        };
+
+        let entries = list_dir_slice(dir_path, 1, 20, 2, &options).await?;
+        assert_eq!(
+            entries,
+            vec![
+                "build/gen.rs".to_string(),
+                "src/main.rs".to_string(),
+                "top.rs".to_string(),
+            ]
+        );
+
+        let entries = list_dir_slice(dir_path, 1, 20, 4, &options).await?;
+        assert_eq!(
+            entries,
+            vec![
+                "build/gen.rs".to_string(),
+                "src/lib/deep/x.rs".to_string(),
+                "src/lib/mod.rs".to_string(),
+                "src/main.rs".to_string(),
+                "top.rs".to_string(),
+            ]
+        );
+
+        let top_level_only = ListDirOptions {
+            pattern: Some(compile_pattern("*.rs")?),
+            ..Default::default()
+        };
+        let entries = list_dir_slice(dir_path, 1, 20, 4, &top_level_only).await?;
+        assert_eq!(entries, vec!["top.rs".to_string()]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn filters_entries_by_type() -> anyhow::Result<()> {
+        let temp = tempdir()?;
+        let dir_path = temp.path();
+        write_filter_fixture(dir_path).await?;
+
+        let directories = ListDirOptions {
+            entry_type: Some(EntryTypeFilter::Directory),
+            ..Default::default()
+        };
+        let entries = list_dir_slice(dir_path, 1, 20, 3, &directories).await?;
+        assert_eq!(
+            entries,
+            vec![
+                "build/".to_string(),
+                "src/".to_string(),
+                "src/lib/".to_string(),
+                "src/lib/deep/".to_string(),
+            ]
         );
+
+        let src_files = ListDirOptions {
+            pattern: Some(compile_pattern("src/**")?),
+            entry_type: Some(EntryTypeFilter::File),
+            ..Default::default()
+        };
+        let entries = list_dir_slice(dir_path, 1, 20, 3, &src_files).await?;
+        assert_eq!(
+            entries,
+            vec![
+                "src/lib/mod.rs".to_string(),
+                "src/main.rs".to_string(),
+                "src/notes.txt".to_string(),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_explicit_tree_with_filters_or_sorting() -> anyhow::Result<()> {
+        let tree = ListDirOptions {
+            output: Some(OutputMode::Tree),
+            ..Default::default()
+        };
+        assert_eq!(tree.validate(), Ok(()));
+
+        let expected = Err(FunctionCallError::RespondToModel(
+            "output \"tree\" cannot be combined with pattern, entry_type, or sorting; use \"entries\" instead".to_string(),
+        ));
+        let filtered_tree = ListDirOptions {
+            pattern: Some(compile_pattern("*.rs")?),
+            ..tree
+        };
+        assert_eq!(filtered_tree.validate(), expected);
+        let sorted_tree = ListDirOptions {
+            sort_by: SortBy::Size,
+            ..tree
+        };
+        assert_eq!(sorted_tree.validate(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_pattern() {
+        let err = compile_pattern("src/[").expect_err("invalid glob");
+        assert!(
+            matches!(err, FunctionCallError::RespondToModel(message) if message.starts_with("invalid pattern:"))
+        );
+    }
+
+    #[tokio::test]
+    async fn pages_cover_every_entry_exactly_once() -> anyhow::Result<()> {
+        let temp = tempdir()?;
+        let dir_path = temp.path();
+        for dir in ["a", "b", "c"] {
+            tokio::fs::create_dir(dir_path.join(dir)).await?;
+            for file in ["x.txt", "y.txt"] {
+                tokio::fs::write(dir_path.join(dir).join(file), b"").await?;
+            }
+        }
+
+        let options = ListDirOptions::default();
+        let full = list_dir_slice(dir_path, 1, 100, 2, &options).await?;
+        let mut paged = Vec::new();
+        let mut offset = 1;
+        while offset <= full.len() {
+            let page = list_dir_slice(dir_path, offset, 4, 2, &options).await?;
+            paged.extend(
+                page.into_iter()
+                    .filter(|line| !line.starts_with("More than")),
+            );
+            offset += 4;
+        }
+        paged.sort();
+        let mut expected = full;
+        expected.sort();
+        assert_eq!(paged, expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sorts_by_modified_time_descending() -> anyhow::Result<()> {
+        let temp = tempdir()?;
+        let dir_path = temp.path();
+        tokio::fs::create_dir(dir_path.join("nested")).await?;
+        let base = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        for (name, age_secs) in [("old.txt", 300), ("nested/newest.txt", 0), ("mid.txt", 100)] {
+            let path = dir_path.join(name);
+            let file = std::fs::File::create(&path)?;
+            file.set_modified(base - std::time::Duration::from_secs(age_secs))?;
+        }
+        let nested = std::fs::File::open(dir_path.join("nested"))?;
+        nested.set_modified(base - std::time::Duration::from_secs(200))?;
+
+        let options = ListDirOptions {
+            sort_by: SortBy::Modified,
+            sort_order: SortOrder::Desc,
+            ..Default::default()
+        };
+        let entries = list_dir_slice(dir_path, 1, 3, 2, &options).await?;
+        assert_eq!(
+            entries,
+            vec![
+                "nested/newest.txt".to_string(),
+                "mid.txt".to_string(),
+                "nested/".to_string(),
+                "More than 3 entries found (4 total); continue with offset 4".to_string(),
+            ]
+        );
+
+        let entries = list_dir_slice(dir_path, 4, 3, 2, &options).await?;
+        assert_eq!(entries, vec!["old.txt".to_string()]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sorts_by_size_with_optional_directory_totals() -> anyhow::Result<()> {
+        let temp = tempdir()?;
+        let dir_path = temp.path();
+        tokio::fs::create_dir(dir_path.join("assets")).await?;
+        tokio::fs::write(dir_path.join("assets").join("a.bin"), vec![0u8; 40]).await?;
+        tokio::fs::write(dir_path.join("assets").join("b.bin"), vec![0u8; 30]).await?;
+        tokio::fs::write(dir_path.join("big.txt"), vec![0u8; 50]).await?;
+        tokio::fs::write(dir_path.join("small.txt"), vec![0u8; 5]).await?;
+
+        let mut options = ListDirOptions {
+            sort_by: SortBy::Size,
+            sort_order: SortOrder::Desc,
+            ..Default::default()
+        };
+        let entries = list_dir_slice(dir_path, 1, 10, 1, &options).await?;
+        assert_eq!(
+            entries,
+            vec![
+                "big.txt (50 bytes)".to_string(),
+                "small.txt (5 bytes)".to_string(),
+                "assets/".to_string(),
+            ]
+        );
+
+        options.compute_dir_sizes = true;
+        let entries = list_dir_slice(dir_path, 1, 10, 2, &options).await?;
+        assert_eq!(
+            entries,
+            vec![
+                "assets/ (70 bytes)".to_string(),
+                "big.txt (50 bytes)".to_string(),
+                "assets/a.bin (40 bytes)".to_string(),
+                "assets/b.bin (30 bytes)".to_string(),
+                "small.txt (5 bytes)".to_string(),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn skips_hidden_entries_when_requested() -> anyhow::Result<()> {
+        let temp = tempdir()?;
+        let dir_path = temp.path();
+        let workflows = dir_path.join(".github").join("workflows");
+        tokio::fs::create_dir_all(&workflows).await?;
+        tokio::fs::write(workflows.join("ci.yml"), b"").await?;
+        let src = dir_path.join("src");
+        tokio::fs::create_dir_all(src.join(".cache")).await?;
+        tokio::fs::write(src.join(".cache").join("blob"), b"").await?;
+        tokio::fs::write(src.join(".env"), b"").await?;
+        tokio::fs::write(src.join("lib.rs"), b"").await?;
+        tokio::fs::write(src.join(".DS_Store"), b"").await?;
+        tokio::fs::write(dir_path.join(".DS_Store"), b"").await?;
+
+        let entries = list_dir_slice(dir_path, 1, 20, 3, &ListDirOptions::default()).await?;
+        assert_eq!(
+            entries,
+            vec![
+                ".github/".to_string(),
+                "  workflows/".to_string(),
+                "    ci.yml".to_string(),
+                "src/".to_string(),
+                "  .cache/".to_string(),
+                "    blob".to_string(),
+                "  .env".to_string(),
+                "  lib.rs".to_string(),
+                "Skipped 2 OS metadata entries such as .DS_Store".to_string(),
+            ]
+        );
+
+        let options = ListDirOptions {
+            include_hidden: false,
+            ..Default::default()
+        };
+        let entries = list_dir_slice(dir_path, 1, 20, 3, &options).await?;
+        assert_eq!(
+            entries,
+            vec![
+                "src/".to_string(),
+                "  lib.rs".to_string(),
+                "Skipped 3 hidden entries (set include_hidden to true to include them)".to_string(),
+                "Skipped 2 OS metadata entries such as .DS_Store".to_string(),
+            ]
+        );
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn follows_symlinks_without_looping() -> anyhow::Result<()> {
+        use std::os::unix::fs::symlink;
+
+        let temp = tempdir()?;
+        let dir_path = temp.path();
+        let real = dir_path.join("real");
+        tokio::fs::create_dir(&real).await?;
+        tokio::fs::write(real.join("file.txt"), b"").await?;
+        symlink("..", real.join("up"))?;
+        symlink("real", dir_path.join("alias"))?;
+
+        let entries = list_dir_slice(dir_path, 1, 20, 4, &ListDirOptions::default()).await?;
+        assert_eq!(
+            entries,
+            vec![
+                "alias@ -> real".to_string(),
+                "real/".to_string(),
+                "  file.txt".to_string(),
+                "  up@ -> ..".to_string(),
+            ]
+        );
+
+        let options = ListDirOptions {
+            follow_symlinks: true,
+            ..Default::default()
+        };
+        let entries = list_dir_slice(dir_path, 1, 20, 4, &options).await?;
+        assert_eq!(
+            entries,
+            vec![
+                "alias@ -> real".to_string(),
+                "  file.txt".to_string(),
+                "  up@ -> ..".to_string(),
+                "real/".to_string(),
+                "  file.txt".to_string(),
+                "  up@ -> ..".to_string(),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn renders_entries_and_summary_output_modes() -> anyhow::Result<()> {
+        let temp = tempdir()?;
+        let dir_path = temp.path();
+        let src = dir_path.join("src");
+        tokio::fs::create_dir_all(src.join("bin")).await?;
+        tokio::fs::create_dir(dir_path.join("docs")).await?;
+        tokio::fs::write(src.join("lib.rs"), vec![0u8; 100]).await?;
+        tokio::fs::write(src.join("bin").join("main.rs"), vec![0u8; 50]).await?;
+        tokio::fs::write(dir_path.join("docs").join("guide.md"), vec![0u8; 30]).await?;
+        tokio::fs::write(dir_path.join("Makefile"), vec![0u8; 10]).await?;
+        tokio::fs::write(dir_path.join(".gitignore"), b"target/\n").await?;
+        tokio::fs::create_dir(dir_path.join("target")).await?;
+        tokio::fs::write(dir_path.join("target").join("out.bin"), vec![0u8; 999]).await?;
+
+        let tree = list_dir_slice(dir_path, 1, 20, 3, &ListDirOptions::default()).await?;
+        assert_eq!(
+            tree,
+            vec![
+                ".gitignore".to_string(),
+                "Makefile".to_string(),
+                "docs/".to_string(),
+                "  guide.md".to_string(),
+                "src/".to_string(),
+                "  bin/".to_string(),
+                "    main.rs".to_string(),
+                "  lib.rs".to_string(),
+                "Skipped 1 entry matched by .gitignore or .ignore (set respect_gitignore to false to include them)".to_string(),
+            ]
+        );
+
+        let flat = ListDirOptions {
+            output: Some(OutputMode::Entries),
+            ..Default::default()
+        };
+        let entries = list_dir_slice(dir_path, 1, 4, 3, &flat).await?;
+        assert_eq!(
+            entries,
+            vec![
+                ".gitignore".to_string(),
+                "Makefile".to_string(),
+                "docs/".to_string(),
+                "src/".to_string(),
+                "More than 4 entries found (8 total); continue with offset 5".to_string(),
+                "Skipped 1 entry matched by .gitignore or .ignore (set respect_gitignore to false to include them)".to_string(),
+            ]
+        );
+
+        let summary = ListDirOptions {
+            output: Some(OutputMode::Summary),
+            include_hidden: false,
+            ..Default::default()
+        };
+        let entries = list_dir_slice(dir_path, 1, 20, 3, &summary).await?;
+        assert_eq!(
+            entries,
+            vec![
+                "Summary of 4 files (190 bytes) within depth 3".to_string(),
+                "By top-level directory:".to_string(),
+                "  src/: 2 files, 150 bytes".to_string(),
+                "  docs/: 1 file, 30 bytes".to_string(),
+                "  ./: 1 file, 10 bytes".to_string(),
+                "By extension:".to_string(),
+                "  .rs: 2 files, 150 bytes".to_string(),
+                "  .md: 1 file, 30 bytes".to_string(),
+                "  (no extension): 1 file, 10 bytes".to_string(),
+                "Skipped 1 entry matched by .gitignore or .ignore (set respect_gitignore to false to include them)".to_string(),
+                "Skipped 1 hidden entry (set include_hidden to true to include them)".to_string(),
+            ]
+        );
+        Ok(())
     }
 
     #[tokio::test]
@@ -461,14 +1511,15 @@ mod tests {
         tokio::fs::write(nested.join("child.txt"), b"child").await?;
         tokio::fs::write(deeper.join("grandchild.txt"), b"deep").await?;
 
-        let entries_depth_three = list_dir_slice(dir_path, 1, 3, 3).await?;
+        let entries_depth_three =
+            list_dir_slice(dir_path, 1, 3, 3, &ListDirOptions::default()).await?;
         assert_eq!(
             entries_depth_three,
             vec![
                 "nested/".to_string(),
                 "  child.txt".to_string(),
                 "root.txt".to_string(),
-                "More than 3 entries found".to_string()
+                "More than 3 entries found (5 total); continue with offset 4".to_string()
             ]
         );
 
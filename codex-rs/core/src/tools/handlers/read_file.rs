@@ -1,9 +1,11 @@
 use std::collections::VecDeque;
+use std::path::Path;
 use std::path::PathBuf;
 
 use async_trait::async_trait;
 use codex_utils_string::take_bytes_at_char_boundary;
 use serde::Deserialize;
+use serde::Serialize;
 
 use crate::function_tool::FunctionCallError;
 use crate::tools::context::ToolInvocation;
@@ -16,6 +18,13 @@ pub struct ReadFileHandler;
 
 const MAX_LINE_LENGTH: usize = 500;
 const TAB_WIDTH: usize = 4;
+const DEFAULT_MAX_BYTES: usize = 200 * 1024;
+const MAX_BATCH_FILES: usize = 10;
+/// Combined budget for the serialized `file_paths` response, on top of each
+/// file's own `max_bytes` cap.
+const MAX_BATCH_BYTES: usize = 400 * 1024;
+/// Serialized size of the `truncated` flag on a cut-down batch entry.
+const TRUNCATED_FLAG_BYTES: usize = r#","truncated":true"#.len();
 
 // TODO(jif) add support for block comments
 const COMMENT_PREFIXES: &[&str] = &["#", "//", "--"];
@@ -24,13 +33,29 @@ const COMMENT_PREFIXES: &[&str] = &["#", "//", "--"];
 #[derive(Deserialize)]
 struct ReadFileArgs {
     /// Absolute path to the file that will be read.
-    file_path: String,
-    /// 1-indexed line number to start reading from; defaults to 1.
+    #[serde(default)]
+    file_path: Option<String>,
+    /// Absolute paths of several files to read with the same options. Mutually
+    /// exclusive with `file_path`.
+    #[serde(default)]
+    file_paths: Vec<String>,
+    /// 1-indexed line number to start reading from; defaults to 1. Negative
+    /// values count back from the end of the file, so `-1` is the last line;
+    /// reading still moves forward from there.
     #[serde(default = "defaults::offset")]
-    offset: usize,
+    offset: i64,
     /// Maximum number of lines to return; defaults to 2000.
     #[serde(default = "defaults::limit")]
     limit: usize,
+    /// Maximum number of bytes of output to return; defaults to 200 KiB.
+    #[serde(default = "defaults::max_bytes")]
+    max_bytes: usize,
+    /// Read the last `tail` lines of the file. Takes precedence over `offset`;
+    /// `limit` still caps how many of those lines are returned. Lines are
+    /// numbered from the end (`L-1` is the last line) so the file never has
+    /// to be scanned from the start.
+    #[serde(default)]
+    tail: Option<usize>,
     /// Determines whether the handler reads a simple slice or indentation-aware block.
     #[serde(default)]
     mode: ReadMode,
@@ -39,7 +64,7 @@ struct ReadFileArgs {
     indentation: Option<IndentationArgs>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
 enum ReadMode {
     Slice,
@@ -65,6 +90,132 @@ struct IndentationArgs {
     max_lines: Option<usize>,
 }
 
+/// Where a read begins.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ReadStart {
+    /// 1-indexed line number counted from the top of the file.
+    Line(usize),
+    /// Line counted back from the end of the file; `1` is the last line.
+    FromEnd(usize),
+    /// The last N lines of the file, capped to the final `limit`.
+    Tail(usize),
+}
+
+/// Per-file read options shared by single and batch reads.
+struct ReadRequest {
+    start: ReadStart,
+    limit: usize,
+    max_bytes: usize,
+    mode: ReadMode,
+    indentation: Option<IndentationArgs>,
+}
+
+impl ReadRequest {
+    /// Validates the options shared by every file in a call.
+    fn from_args(args: &ReadFileArgs) -> Result<Self, FunctionCallError> {
+        let ReadFileArgs {
+            offset,
+            limit,
+            max_bytes,
+            tail,
+            mode,
+            indentation,
+            ..
+        } = args;
+
+        if *offset == 0 {
+            return Err(FunctionCallError::RespondToModel(
+                "offset must be a 1-indexed line number".to_string(),
+            ));
+        }
+
+        if *tail == Some(0) {
+            return Err(FunctionCallError::RespondToModel(
+                "tail must be greater than zero".to_string(),
+            ));
+        }
+
+        if *limit == 0 {
+            return Err(FunctionCallError::RespondToModel(
+                "limit must be greater than zero".to_string(),
+            ));
+        }
+
+        if *max_bytes == 0 {
+            return Err(FunctionCallError::RespondToModel(
+                "max_bytes must be greater than zero".to_string(),
+            ));
+        }
+
+        let start = match (tail, usize::try_from(*offset)) {
+            (Some(count), _) => ReadStart::Tail(*count),
+            (None, Ok(line)) => ReadStart::Line(line),
+            (None, Err(_)) => {
+                ReadStart::FromEnd(usize::try_from(offset.unsigned_abs()).unwrap_or(usize::MAX))
+            }
+        };
+
+        if matches!(mode, ReadMode::Indentation) && !matches!(start, ReadStart::Line(_)) {
+            return Err(FunctionCallError::RespondToModel(
+                "tail reads and negative offsets are only supported in slice mode".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            start,
+            limit: *limit,
+            max_bytes: *max_bytes,
+            mode: *mode,
+            indentation: indentation.clone(),
+        })
+    }
+
+    async fn read(&self, path: &Path) -> Result<Vec<String>, FunctionCallError> {
+        let collected = match (self.mode, self.start) {
+            (ReadMode::Slice, ReadStart::Line(offset)) => {
+                slice::read(path, offset, self.limit).await?
+            }
+            (ReadMode::Slice, ReadStart::FromEnd(from_end)) => {
+                tail::read_from(path, from_end, self.limit).await?
+            }
+            (ReadMode::Slice, ReadStart::Tail(count)) => {
+                tail::read(path, count, self.limit).await?
+            }
+            (ReadMode::Indentation, ReadStart::Line(offset)) => {
+                let indentation = self.indentation.clone().unwrap_or_default();
+                indentation::read_block(path, offset, self.limit, indentation).await?
+            }
+            (ReadMode::Indentation, ReadStart::FromEnd(_) | ReadStart::Tail(_)) => {
+                unreachable!("from_args only allows line offsets in indentation mode")
+            }
+        };
+        Ok(apply_byte_budget(path, collected, self.max_bytes, self.kept_end()).await)
+    }
+
+    /// Tail reads exist to see the end of a file, so that is the part that
+    /// survives the byte budget. Every other read pages forward.
+    fn kept_end(&self) -> KeptEnd {
+        match self.start {
+            ReadStart::Tail(_) => KeptEnd::Last,
+            ReadStart::Line(_) | ReadStart::FromEnd(_) => KeptEnd::First,
+        }
+    }
+}
+
+/// One entry of the JSON array returned for a `file_paths` batch.
+#[derive(Debug, PartialEq, Serialize)]
+struct BatchReadResult {
+    file_path: String,
+    success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    /// Set when the batch-wide budget cut this file short.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    truncated: bool,
+}
+
 #[derive(Clone, Debug)]
 struct LineRecord {
     number: usize,
@@ -113,48 +264,188 @@ impl ToolHandler for ReadFileHandler {
             ))
         })?;
 
+        let request = ReadRequest::from_args(&args)?;
         let ReadFileArgs {
             file_path,
-            offset,
-            limit,
-            mode,
-            indentation,
+            file_paths,
+            ..
         } = args;
 
-        if offset == 0 {
-            return Err(FunctionCallError::RespondToModel(
-                "offset must be a 1-indexed line number".to_string(),
-            ));
+        if file_paths.len() > MAX_BATCH_FILES {
+            return Err(FunctionCallError::RespondToModel(format!(
+                "file_paths accepts at most {MAX_BATCH_FILES} files"
+            )));
         }
 
-        if limit == 0 {
-            return Err(FunctionCallError::RespondToModel(
-                "limit must be greater than zero".to_string(),
-            ));
-        }
-
-        let path = PathBuf::from(&file_path);
-        if !path.is_absolute() {
-            return Err(FunctionCallError::RespondToModel(
-                "file_path must be an absolute path".to_string(),
-            ));
-        }
-
-        let collected = match mode {
-            ReadMode::Slice => slice::read(&path, offset, limit).await?,
-            ReadMode::Indentation => {
-                let indentation = indentation.unwrap_or_default();
-                indentation::read_block(&path, offset, limit, indentation).await?
+        let content = match (file_path, file_paths.is_empty()) {
+            (Some(file_path), true) => {
+                let path = absolute_path(&file_path, "file_path")?;
+                request.read(&path).await?.join("\n")
+            }
+            (None, false) => {
+                let results = read_batch(&file_paths, &request).await?;
+                serde_json::to_string(&results).map_err(|err| {
+                    FunctionCallError::RespondToModel(format!(
+                        "failed to serialize read_file results: {err}"
+                    ))
+                })?
+            }
+            (Some(_), false) => {
+                return Err(FunctionCallError::RespondToModel(
+                    "provide either file_path or file_paths, not both".to_string(),
+                ));
+            }
+            (None, true) => {
+                return Err(FunctionCallError::RespondToModel(
+                    "file_path is required".to_string(),
+                ));
             }
         };
+
         Ok(ToolOutput::Function {
-            content: collected.join("\n"),
+            content,
             content_items: None,
             success: Some(true),
         })
     }
 }
 
+fn absolute_path(file_path: &str, field: &str) -> Result<PathBuf, FunctionCallError> {
+    let path = PathBuf::from(file_path);
+    if !path.is_absolute() {
+        return Err(FunctionCallError::RespondToModel(format!(
+            "{field} must be an absolute path"
+        )));
+    }
+    Ok(path)
+}
+
+/// Reads every path with the same options. A path that is not absolute or
+/// cannot be read is reported in its own entry instead of failing the batch.
+/// When the serialized results would exceed `MAX_BATCH_BYTES`, the largest
+/// files are cut down first.
+async fn read_batch(
+    file_paths: &[String],
+    request: &ReadRequest,
+) -> Result<Vec<BatchReadResult>, FunctionCallError> {
+    let mut reads = Vec::with_capacity(file_paths.len());
+    for file_path in file_paths {
+        let read = match absolute_path(file_path, "file_paths") {
+            Ok(path) => request.read(&path).await.map(|lines| (path, lines)),
+            Err(err) => Err(err),
+        };
+        reads.push(read);
+    }
+
+    let mut results: Vec<BatchReadResult> = file_paths
+        .iter()
+        .zip(&reads)
+        .map(|(file_path, read)| match read {
+            Ok((_, lines)) => BatchReadResult {
+                file_path: file_path.clone(),
+                success: true,
+                content: Some(lines.join("\n")),
+                error: None,
+                truncated: false,
+            },
+            Err(err) => BatchReadResult {
+                file_path: file_path.clone(),
+                success: false,
+                content: None,
+                error: Some(err.to_string()),
+                truncated: false,
+            },
+        })
+        .collect();
+
+    // Budget in serialized bytes, since JSON escaping can make the response
+    // noticeably larger than the raw text.
+    let content_sizes: Vec<usize> = results
+        .iter()
+        .map(|result| result.content.as_deref().map_or(0, json_escaped_len))
+        .collect();
+    let serialized_len = serde_json::to_string(&results)
+        .map_err(|err| {
+            FunctionCallError::RespondToModel(format!(
+                "failed to serialize read_file results: {err}"
+            ))
+        })?
+        .len();
+    let envelope =
+        serialized_len - content_sizes.iter().sum::<usize>() + TRUNCATED_FLAG_BYTES * results.len();
+    let Some(cap) = batch_share(&content_sizes, MAX_BATCH_BYTES.saturating_sub(envelope)) else {
+        return Ok(results);
+    };
+
+    for ((result, read), size) in results.iter_mut().zip(reads).zip(content_sizes) {
+        let Ok((path, lines)) = read else {
+            continue;
+        };
+        if size <= cap {
+            continue;
+        }
+        result.content =
+            Some(shrink_to_serialized_len(&path, lines, cap, request.kept_end()).await);
+        result.truncated = true;
+    }
+    Ok(results)
+}
+
+/// Applies the byte budget to `lines`, tightening it until the joined output
+/// fits in `cap` bytes once escaped for JSON.
+async fn shrink_to_serialized_len(
+    path: &Path,
+    lines: Vec<String>,
+    cap: usize,
+    kept_end: KeptEnd,
+) -> String {
+    let mut budget = cap.max(1);
+    loop {
+        let content = apply_byte_budget(path, lines.clone(), budget, kept_end)
+            .await
+            .join("\n");
+        let size = json_escaped_len(&content);
+        if size <= cap || budget == 1 {
+            return content;
+        }
+        // Escaping overhead is roughly uniform, so scale the raw budget down
+        // by how far over the cap this attempt landed.
+        budget = (budget * cap / size).clamp(1, budget - 1);
+    }
+}
+
+/// Length of `text` once serialized as a JSON string, without the quotes.
+fn json_escaped_len(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c {
+            '"' | '\\' | '\n' | '\r' | '\t' | '\u{08}' | '\u{0c}' => 2,
+            c if c < ' ' => 6,
+            c => c.len_utf8(),
+        })
+        .sum()
+}
+
+/// Returns the largest per-file size that keeps the total of `sizes` within
+/// `budget` when only files above it are shortened, or `None` if everything
+/// already fits.
+fn batch_share(sizes: &[usize], budget: usize) -> Option<usize> {
+    if sizes.iter().sum::<usize>() <= budget {
+        return None;
+    }
+
+    let mut sorted = sizes.to_vec();
+    sorted.sort_unstable();
+    let mut remaining = budget;
+    for (index, size) in sorted.iter().enumerate() {
+        let share = remaining / (sorted.len() - index);
+        if *size > share {
+            return Some(share);
+        }
+        remaining -= size;
+    }
+    Some(remaining)
+}
+
 mod slice {
     use crate::function_tool::FunctionCallError;
     use crate::tools::handlers::read_file::format_line;
@@ -222,6 +513,147 @@ mod slice {
     }
 }
 
+mod tail {
+    use crate::function_tool::FunctionCallError;
+    use crate::tools::handlers::read_file::format_line;
+    use std::io::SeekFrom;
+    use std::path::Path;
+    use tokio::fs::File;
+    use tokio::io::AsyncBufReadExt;
+    use tokio::io::AsyncReadExt;
+    use tokio::io::AsyncSeekExt;
+    use tokio::io::BufReader;
+
+    const CHUNK_SIZE: usize = 8 * 1024;
+
+    /// Reads the last `count` lines of `path`, capped to the final `limit`
+    /// lines, scanning backwards from the end so large files are never
+    /// buffered in full. Files shorter than `count` lines are returned whole.
+    /// Lines are labelled relative to the end of the file, matching negative
+    /// offsets: the last line is `L-1`.
+    pub async fn read(
+        path: &Path,
+        count: usize,
+        limit: usize,
+    ) -> Result<Vec<String>, FunctionCallError> {
+        let mut file = File::open(path).await.map_err(read_error)?;
+        let len = file.metadata().await.map_err(read_error)?.len();
+        let (start, available) = find_tail_start(&mut file, len, count.min(limit)).await?;
+        let lines = read_lines(file, start, available).await?;
+        Ok(label_from_end(lines, available))
+    }
+
+    /// Reads up to `limit` lines forward from the line `from_end` lines before
+    /// the end of `path`, so `from_end == 1` starts at the last line. Like
+    /// `read`, only the end of the file is scanned.
+    pub async fn read_from(
+        path: &Path,
+        from_end: usize,
+        limit: usize,
+    ) -> Result<Vec<String>, FunctionCallError> {
+        let mut file = File::open(path).await.map_err(read_error)?;
+        let len = file.metadata().await.map_err(read_error)?.len();
+        let (start, available) = find_tail_start(&mut file, len, from_end).await?;
+        if available < from_end {
+            return Err(FunctionCallError::RespondToModel(
+                "offset exceeds file length".to_string(),
+            ));
+        }
+        let lines = read_lines(file, start, limit.min(available)).await?;
+        Ok(label_from_end(lines, available))
+    }
+
+    async fn read_lines(
+        mut file: File,
+        start: u64,
+        max_lines: usize,
+    ) -> Result<Vec<String>, FunctionCallError> {
+        file.seek(SeekFrom::Start(start))
+            .await
+            .map_err(read_error)?;
+        let mut reader = BufReader::new(file);
+        let mut lines = Vec::new();
+        let mut buffer = Vec::new();
+
+        while lines.len() < max_lines {
+            buffer.clear();
+            let bytes_read = reader
+                .read_until(b'\n', &mut buffer)
+                .await
+                .map_err(read_error)?;
+
+            if bytes_read == 0 {
+                break;
+            }
+
+            if buffer.last() == Some(&b'\n') {
+                buffer.pop();
+                if buffer.last() == Some(&b'\r') {
+                    buffer.pop();
+                }
+            }
+
+            lines.push(format_line(&buffer));
+        }
+
+        Ok(lines)
+    }
+
+    /// Numbers `lines` from the end of the file, where the first of them is
+    /// `first_from_end` lines before it.
+    fn label_from_end(lines: Vec<String>, first_from_end: usize) -> Vec<String> {
+        lines
+            .into_iter()
+            .enumerate()
+            .map(|(idx, line)| format!("L-{}: {line}", first_from_end - idx))
+            .collect()
+    }
+
+    /// Returns the byte offset at which the last `count` lines begin and how
+    /// many lines follow it, which is fewer than `count` when the file is
+    /// shorter. A newline terminating the final line does not start a new line.
+    async fn find_tail_start(
+        file: &mut File,
+        len: u64,
+        count: usize,
+    ) -> Result<(u64, usize), FunctionCallError> {
+        let mut buffer = vec![0u8; CHUNK_SIZE];
+        let mut position = len;
+        let mut newlines = 0usize;
+
+        while position > 0 {
+            let chunk_len = CHUNK_SIZE.min(usize::try_from(position).unwrap_or(CHUNK_SIZE));
+            position -= chunk_len as u64;
+            file.seek(SeekFrom::Start(position))
+                .await
+                .map_err(read_error)?;
+            file.read_exact(&mut buffer[..chunk_len])
+                .await
+                .map_err(read_error)?;
+
+            for (idx, byte) in buffer[..chunk_len].iter().enumerate().rev() {
+                if *byte != b'\n' {
+                    continue;
+                }
+                let absolute = position + idx as u64;
+                if absolute + 1 == len {
+                    continue;
+                }
+                newlines += 1;
+                if newlines == count {
+                    return Ok((absolute + 1, count));
+                }
+            }
+        }
+
+        Ok((0, newlines + usize::from(len > 0)))
+    }
+
+    fn read_error(err: std::io::Error) -> FunctionCallError {
+        FunctionCallError::RespondToModel(format!("failed to read file: {err}"))
+    }
+}
+
 mod indentation {
     use crate::function_tool::FunctionCallError;
     use crate::tools::handlers::read_file::IndentationArgs;
@@ -431,6 +863,78 @@ mod indentation {
     }
 }
 
+/// Which end of the collected lines `apply_byte_budget` keeps.
+#[derive(Clone, Copy)]
+enum KeptEnd {
+    First,
+    Last,
+}
+
+/// Caps the rendered output at `max_bytes`, notice included, adding a notice
+/// with the file size when lines had to be dropped so the model knows how to
+/// page to them. The notice goes on the side where lines were dropped.
+async fn apply_byte_budget(
+    path: &Path,
+    lines: Vec<String>,
+    max_bytes: usize,
+    kept_end: KeptEnd,
+) -> Vec<String> {
+    let rendered_bytes: usize = lines.iter().map(|line| line.len() + 1).sum();
+    if rendered_bytes <= max_bytes {
+        return lines;
+    }
+
+    let hint = match kept_end {
+        KeptEnd::First => "use offset and limit to read further",
+        KeptEnd::Last => "use a negative offset to read earlier lines",
+    };
+    let notice = match tokio::fs::metadata(path).await {
+        Ok(metadata) => format!(
+            "[truncated: output exceeded {max_bytes} bytes (file is {} bytes); {hint}]",
+            metadata.len()
+        ),
+        Err(_) => format!("[truncated: output exceeded {max_bytes} bytes; {hint}]"),
+    };
+    // The notice counts against the budget too, along with its newline.
+    let line_budget = max_bytes.saturating_sub(notice.len() + 1);
+
+    match kept_end {
+        KeptEnd::First => {
+            let mut kept = take_within_budget(lines, line_budget);
+            kept.push(notice);
+            kept
+        }
+        KeptEnd::Last => {
+            let mut kept = take_within_budget(lines.into_iter().rev(), line_budget);
+            kept.push(notice);
+            kept.reverse();
+            kept
+        }
+    }
+}
+
+/// Takes lines in iteration order until the next one would exceed
+/// `max_bytes`.
+fn take_within_budget(lines: impl IntoIterator<Item = String>, max_bytes: usize) -> Vec<String> {
+    let mut used = 0usize;
+    let mut kept = Vec::new();
+
+    for line in lines {
+        // Account for the newline used to join lines in the final output.
+        let cost = line.len() + 1;
+        if used + cost > max_bytes {
+            if kept.is_empty() && max_bytes > 0 {
+                kept.push(take_bytes_at_char_boundary(&line, max_bytes).to_string());
+            }
+            return kept;
+        }
+        used += cost;
+        kept.push(line);
+    }
+
+    kept
+}
+
 fn format_line(bytes: &[u8]) -> String {
     let decoded = String::from_utf8_lossy(bytes);
     if decoded.len() > MAX_LINE_LENGTH {
@@ -470,7 +974,7 @@ mod defaults {
         }
     }
 
-    pub fn offset() -> usize {
+    pub fn offset() -> i64 {
         1
     }
 
@@ -478,6 +982,10 @@ mod defaults {
         2000
     }
 
+    pub fn max_bytes() -> usize {
+        DEFAULT_MAX_BYTES
+    }
+
     pub fn max_levels() -> usize {
         0
     }
@@ -588,6 +1096,358 @@ third
         Ok(())
     }
 
+    #[tokio::test]
+    async fn truncates_output_beyond_byte_budget() -> anyhow::Result<()> {
+        let mut temp = NamedTempFile::new()?;
+        use std::io::Write as _;
+        let line = "y".repeat(100);
+        for _ in 0..5000 {
+            writeln!(temp, "{line}")?;
+        }
+        let file_size = temp.as_file().metadata()?.len();
+
+        let lines = read(temp.path(), 1, defaults::limit()).await?;
+        let budgeted =
+            apply_byte_budget(temp.path(), lines, DEFAULT_MAX_BYTES, KeptEnd::First).await;
+
+        let (notice, kept) = budgeted.split_last().expect("notice line");
+        assert_eq!(
+            notice,
+            &format!(
+                "[truncated: output exceeded {DEFAULT_MAX_BYTES} bytes (file is {file_size} bytes); use offset and limit to read further]"
+            )
+        );
+        let rendered_bytes: usize = budgeted.iter().map(|line| line.len() + 1).sum();
+        assert!(rendered_bytes <= DEFAULT_MAX_BYTES);
+        assert_eq!(kept.first(), Some(&format!("L1: {line}")));
+        assert_eq!(kept.last(), Some(&format!("L{}: {line}", kept.len())));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn byte_budget_applies_to_requested_range() -> anyhow::Result<()> {
+        let mut temp = NamedTempFile::new()?;
+        use std::io::Write as _;
+        let padding = "x".repeat(52);
+        for idx in 1..=10 {
+            writeln!(temp, "line {idx:02} {padding}")?;
+        }
+
+        // Each rendered line is 64 bytes plus the joining newline; after the
+        // notice a 240-byte budget has room for two of the four requested.
+        let lines = read(temp.path(), 5, 4).await?;
+        let budgeted = apply_byte_budget(temp.path(), lines, 240, KeptEnd::First).await;
+        assert_eq!(
+            budgeted,
+            vec![
+                format!("L5: line 05 {padding}"),
+                format!("L6: line 06 {padding}"),
+                "[truncated: output exceeded 240 bytes (file is 610 bytes); use offset and limit to read further]".to_string(),
+            ]
+        );
+        let rendered_bytes: usize = budgeted.iter().map(|line| line.len() + 1).sum();
+        assert!(rendered_bytes <= 240);
+
+        let lines = read(temp.path(), 5, 2).await?;
+        let budgeted = apply_byte_budget(temp.path(), lines, 240, KeptEnd::First).await;
+        assert_eq!(
+            budgeted,
+            vec![
+                format!("L5: line 05 {padding}"),
+                format!("L6: line 06 {padding}"),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tail_reads_last_lines() -> anyhow::Result<()> {
+        let mut temp = NamedTempFile::new()?;
+        use std::io::Write as _;
+        write!(
+            temp,
+            "one
+two
+three
+four
+"
+        )?;
+
+        let lines = super::tail::read(temp.path(), 2, 10).await?;
+        assert_eq!(
+            lines,
+            vec!["L-2: three".to_string(), "L-1: four".to_string()]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tail_without_trailing_newline() -> anyhow::Result<()> {
+        let mut temp = NamedTempFile::new()?;
+        use std::io::Write as _;
+        write!(temp, "one\r\ntwo\r\nthree")?;
+
+        let lines = super::tail::read(temp.path(), 2, 10).await?;
+        assert_eq!(
+            lines,
+            vec!["L-2: two".to_string(), "L-1: three".to_string()]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tail_longer_than_file_returns_whole_file() -> anyhow::Result<()> {
+        let mut temp = NamedTempFile::new()?;
+        use std::io::Write as _;
+        write!(
+            temp,
+            "alpha
+beta
+"
+        )?;
+
+        let lines = super::tail::read(temp.path(), 50, 10).await?;
+        assert_eq!(
+            lines,
+            vec!["L-2: alpha".to_string(), "L-1: beta".to_string()]
+        );
+
+        let empty = NamedTempFile::new()?;
+        let lines = super::tail::read(empty.path(), 5, 10).await?;
+        assert_eq!(lines, Vec::<String>::new());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tail_spans_multiple_chunks_and_respects_limit() -> anyhow::Result<()> {
+        let mut temp = NamedTempFile::new()?;
+        use std::io::Write as _;
+        for idx in 1..=3000 {
+            writeln!(temp, "line number {idx}")?;
+        }
+
+        let lines = super::tail::read(temp.path(), 1500, 3).await?;
+        assert_eq!(
+            lines,
+            vec![
+                "L-3: line number 2998".to_string(),
+                "L-2: line number 2999".to_string(),
+                "L-1: line number 3000".to_string(),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn byte_budget_keeps_the_end_of_tail_reads() -> anyhow::Result<()> {
+        let mut temp = NamedTempFile::new()?;
+        use std::io::Write as _;
+        let padding = "x".repeat(52);
+        for idx in 1..=10 {
+            writeln!(temp, "line {idx:02} {padding}")?;
+        }
+
+        let request = ReadRequest {
+            start: ReadStart::Tail(5),
+            ..slice_request(250)
+        };
+        let lines = request.read(temp.path()).await?;
+        assert_eq!(
+            lines,
+            vec![
+                "[truncated: output exceeded 250 bytes (file is 610 bytes); use a negative offset to read earlier lines]".to_string(),
+                format!("L-2: line 09 {padding}"),
+                format!("L-1: line 10 {padding}"),
+            ]
+        );
+        let rendered_bytes: usize = lines.iter().map(|line| line.len() + 1).sum();
+        assert!(rendered_bytes <= 250);
+        Ok(())
+    }
+
+    fn slice_request(max_bytes: usize) -> ReadRequest {
+        ReadRequest {
+            start: ReadStart::Line(1),
+            limit: defaults::limit(),
+            max_bytes,
+            mode: ReadMode::Slice,
+            indentation: None,
+        }
+    }
+
+    async fn read_with_args(
+        path: &Path,
+        arguments: &str,
+    ) -> Result<Vec<String>, FunctionCallError> {
+        let args: ReadFileArgs = serde_json::from_str(arguments).expect("parse arguments");
+        ReadRequest::from_args(&args)?.read(path).await
+    }
+
+    #[tokio::test]
+    async fn negative_offset_reads_forward_from_the_end() -> anyhow::Result<()> {
+        let mut temp = NamedTempFile::new()?;
+        use std::io::Write as _;
+        for idx in 1..=10 {
+            writeln!(temp, "line {idx:02}")?;
+        }
+
+        let lines = read_with_args(temp.path(), r#"{"offset": -5, "limit": 2}"#).await?;
+        assert_eq!(
+            lines,
+            vec!["L-5: line 06".to_string(), "L-4: line 07".to_string()]
+        );
+
+        let lines = read_with_args(temp.path(), r#"{"offset": -2, "limit": 5}"#).await?;
+        assert_eq!(
+            lines,
+            vec!["L-2: line 09".to_string(), "L-1: line 10".to_string()]
+        );
+
+        let lines = read_with_args(temp.path(), r#"{"offset": -5, "tail": 2}"#).await?;
+        assert_eq!(
+            lines,
+            vec!["L-2: line 09".to_string(), "L-1: line 10".to_string()]
+        );
+
+        let err = read_with_args(temp.path(), r#"{"offset": -11}"#)
+            .await
+            .expect_err("offset before the start of the file");
+        assert_eq!(
+            err,
+            FunctionCallError::RespondToModel("offset exceeds file length".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn indentation_mode_rejects_reads_from_the_end_up_front() {
+        for arguments in [
+            r#"{"file_paths": ["/a", "/b"], "mode": "indentation", "tail": 3}"#,
+            r#"{"file_paths": ["/a", "/b"], "mode": "indentation", "offset": -3}"#,
+        ] {
+            let args: ReadFileArgs = serde_json::from_str(arguments).expect("parse arguments");
+            let err = ReadRequest::from_args(&args)
+                .err()
+                .expect("indentation mode with a read from the end");
+            assert_eq!(
+                err,
+                FunctionCallError::RespondToModel(
+                    "tail reads and negative offsets are only supported in slice mode".to_string()
+                )
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_reports_per_file_results() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let first = dir.path().join("first.txt");
+        let second = dir.path().join("second.txt");
+        tokio::fs::write(&first, "alpha\nbeta\n").await?;
+        tokio::fs::write(&second, "gamma\n").await?;
+        let missing = dir.path().join("missing.txt");
+
+        let mut file_paths = [&first, &missing, &second]
+            .map(|path| path.to_string_lossy().into_owned())
+            .to_vec();
+        file_paths.push("relative.txt".to_string());
+        let results = read_batch(&file_paths, &slice_request(DEFAULT_MAX_BYTES)).await?;
+        let missing_error = results[1].error.clone();
+        assert!(
+            missing_error
+                .as_deref()
+                .is_some_and(|error| error.starts_with("failed to read file:"))
+        );
+        assert_eq!(
+            results,
+            vec![
+                BatchReadResult {
+                    file_path: file_paths[0].clone(),
+                    success: true,
+                    content: Some("L1: alpha\nL2: beta".to_string()),
+                    error: None,
+                    truncated: false,
+                },
+                BatchReadResult {
+                    file_path: file_paths[1].clone(),
+                    success: false,
+                    content: None,
+                    error: missing_error,
+                    truncated: false,
+                },
+                BatchReadResult {
+                    file_path: file_paths[2].clone(),
+                    success: true,
+                    content: Some("L1: gamma".to_string()),
+                    error: None,
+                    truncated: false,
+                },
+                BatchReadResult {
+                    file_path: "relative.txt".to_string(),
+                    success: false,
+                    content: None,
+                    error: Some("file_paths must be an absolute path".to_string()),
+                    truncated: false,
+                },
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn batch_truncates_largest_files_first() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        // Quotes and backslashes double in size once escaped for JSON.
+        let line = r#"x = "\\"; "#.repeat(9);
+        let mut file_paths = Vec::new();
+        for (name, line_count) in [("large.txt", 4000), ("medium.txt", 1000), ("small.txt", 1)] {
+            let path = dir.path().join(name);
+            tokio::fs::write(&path, format!("{line}\n").repeat(line_count)).await?;
+            file_paths.push(path.to_string_lossy().into_owned());
+        }
+
+        let request = ReadRequest {
+            limit: 10_000,
+            ..slice_request(300 * 1024)
+        };
+        let results = read_batch(&file_paths, &request).await?;
+        let truncated: Vec<bool> = results.iter().map(|result| result.truncated).collect();
+        assert_eq!(truncated, vec![true, false, false]);
+        let total = serde_json::to_string(&results)?.len();
+        assert!(
+            total <= MAX_BATCH_BYTES,
+            "serialized output was {total} bytes"
+        );
+        assert!(
+            results[0]
+                .content
+                .as_deref()
+                .is_some_and(|content| content.ends_with("use offset and limit to read further]"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn json_escaped_len_counts_escape_sequences() {
+        for text in [
+            "plain",
+            "a\"b\\c",
+            "line\nnext\ttab",
+            "\u{1}ctl",
+            "émoji 🎉",
+        ] {
+            let serialized = serde_json::to_string(text).expect("serialize");
+            assert_eq!(json_escaped_len(text), serialized.len() - 2, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn batch_share_only_limits_files_above_the_fair_share() {
+        assert_eq!(batch_share(&[10, 20], 100), None);
+        assert_eq!(batch_share(&[10, 200, 500], 310), Some(150));
+        assert_eq!(batch_share(&[300, 300], 100), Some(50));
+    }
+
     #[tokio::test]
     async fn indentation_mode_captures_block() -> anyhow::Result<()> {
         let mut temp = NamedTempFile::new()?;
@@ -536,11 +536,25 @@ fn create_read_file_tool() -> ToolSpec {
             description: Some("Absolute path to the file".to_string()),
         },
     );
+    properties.insert(
+        "file_paths".to_string(),
+        JsonSchema::Array {
+            items: Box::new(JsonSchema::String { description: None }),
+            description: Some(
+                "Absolute paths of up to 10 files to read with the same options, instead of \
+                 file_path. Returns a JSON array with a result per file."
+                    .to_string(),
+            ),
+        },
+    );
     properties.insert(
         "offset".to_string(),
         JsonSchema::Number {
             description: Some(
-                "The line number to start reading from. Must be 1 or greater.".to_string(),
+                "The line number to start reading from. Must be 1 or greater, or negative to \
+                 count back from the end of the file (-1 is the last line). Reading always \
+                 continues forward from the start line."
+                    .to_string(),
             ),
         },
     );
@@ -550,6 +564,27 @@ fn create_read_file_tool() -> ToolSpec {
             description: Some("The maximum number of lines to return.".to_string()),
         },
     );
+    properties.insert(
+        "tail".to_string(),
+        JsonSchema::Number {
+            description: Some(
+                "Read the last N lines of the file instead of starting at offset. Lines are \
+                 numbered from the end (L-1 is the last line), matching negative offsets. Only \
+                 supported in slice mode."
+                    .to_string(),
+            ),
+        },
+    );
+    properties.insert(
+        "max_bytes".to_string(),
+        JsonSchema::Number {
+            description: Some(
+                "The maximum number of bytes of output to return (defaults to 204800). Longer \
+                 output is truncated with a notice."
+                    .to_string(),
+            ),
+        },
+    );
     properties.insert(
         "mode".to_string(),
         JsonSchema::String {
@@ -620,7 +655,7 @@ fn create_read_file_tool() -> ToolSpec {
         strict: false,
         parameters: JsonSchema::Object {
             properties,
-            required: Some(vec!["file_path".to_string()]),
+            required: None,
             additional_properties: Some(false.into()),
         },
     })
@@ -645,7 +680,9 @@ fn create_list_dir_tool() -> ToolSpec {
     properties.insert(
         "limit".to_string(),
         JsonSchema::Number {
-            description: Some("The maximum number of entries to return.".to_string()),
+            description: Some(
+                "The maximum number of entries to return (defaults to 500).".to_string(),
+            ),
         },
     );
     properties.insert(
@@ -656,6 +693,83 @@ fn create_list_dir_tool() -> ToolSpec {
             ),
         },
     );
+    properties.insert(
+        "respect_gitignore".to_string(),
+        JsonSchema::Boolean {
+            description: Some(
+                "Skip entries matched by .gitignore or .ignore files (defaults to true)."
+                    .to_string(),
+            ),
+        },
+    );
+    properties.insert(
+        "include_hidden".to_string(),
+        JsonSchema::Boolean {
+            description: Some(
+                "Include dotfiles and dot-directories (defaults to true). OS metadata files such as .DS_Store are always skipped."
+                    .to_string(),
+            ),
+        },
+    );
+    properties.insert(
+        "follow_symlinks".to_string(),
+        JsonSchema::Boolean {
+            description: Some(
+                "Walk into symlinked directories (defaults to false). Symlink loops are not followed."
+                    .to_string(),
+            ),
+        },
+    );
+    properties.insert(
+        "pattern".to_string(),
+        JsonSchema::String {
+            description: Some(
+                "Optional glob matched against paths relative to dir_path (e.g. \"**/*.rs\"). \
+                 Non-matching directories are still traversed but not listed."
+                    .to_string(),
+            ),
+        },
+    );
+    properties.insert(
+        "entry_type".to_string(),
+        JsonSchema::String {
+            description: Some(
+                "Optional entry kind to list: \"file\" or \"directory\".".to_string(),
+            ),
+        },
+    );
+    properties.insert(
+        "sort_by".to_string(),
+        JsonSchema::String {
+            description: Some(
+                "Optional sort key: \"name\" (default), \"size\", or \"modified\".".to_string(),
+            ),
+        },
+    );
+    properties.insert(
+        "sort_order".to_string(),
+        JsonSchema::String {
+            description: Some("Optional sort order: \"asc\" (default) or \"desc\".".to_string()),
+        },
+    );
+    properties.insert(
+        "compute_dir_sizes".to_string(),
+        JsonSchema::Boolean {
+            description: Some(
+                "When sorting by size, report directories as the total size of the files beneath them within the listed depth instead of 0.".to_string(),
+            ),
+        },
+    );
+
+    properties.insert(
+        "output".to_string(),
+        JsonSchema::String {
+            description: Some(
+                "Optional output format: \"tree\" indents entries beneath their parent, \"entries\" lists relative paths, and \"summary\" reports file counts and sizes per top-level directory and per extension. Defaults to \"tree\", or \"entries\" when pattern, entry_type, or sorting is set; an explicit \"tree\" cannot be combined with them."
+                    .to_string(),
+            ),
+        },
+    );
 
     ToolSpec::Function(ResponsesApiTool {
         name: "list_dir".to_string(),